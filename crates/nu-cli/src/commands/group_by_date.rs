@@ -1,7 +1,9 @@
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tagged;
 
 pub struct GroupByDate;
@@ -10,6 +12,9 @@ pub struct GroupByDate;
 pub struct GroupByDateArgs {
     column_name: Option<Tagged<String>>,
     format: Option<Tagged<String>>,
+    period: Option<Tagged<String>>,
+    timezone: Option<Tagged<String>>,
+    every: Option<Tagged<String>>,
 }
 
 #[async_trait]
@@ -31,6 +36,24 @@ impl WholeStreamCommand for GroupByDate {
                 "Specify date and time formatting",
                 Some('f'),
             )
+            .named(
+                "period",
+                SyntaxShape::String,
+                "Bucket dates by calendar period: day, week, month, quarter or year",
+                Some('p'),
+            )
+            .named(
+                "timezone",
+                SyntaxShape::String,
+                "Convert dates into this timezone before grouping (IANA name or +HH:MM offset)",
+                Some('z'),
+            )
+            .named(
+                "every",
+                SyntaxShape::String,
+                "Bucket dates into fixed-width intervals, e.g. 30sec, 15min, 1h, 7day",
+                Some('e'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -54,8 +77,43 @@ impl WholeStreamCommand for GroupByDate {
     }
 }
 
+/// The calendar unit a date is floored to when bucketing with `--period`.
+enum Period {
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
 enum Grouper {
     ByDate(Option<String>),
+    ByPeriod(Period),
+    ByEvery { interval: i64, format: Option<String> },
+}
+
+/// A resolved `--timezone` argument: either a named IANA zone or a fixed offset.
+enum Timezone {
+    Named(Tz),
+    Fixed(FixedOffset),
+}
+
+impl Timezone {
+    /// Shift a UTC datetime into this zone and render it with `fmt`.
+    fn format(&self, dt: DateTime<Utc>, fmt: &str) -> String {
+        match self {
+            Timezone::Named(tz) => dt.with_timezone(tz).format(fmt).to_string(),
+            Timezone::Fixed(offset) => dt.with_timezone(offset).format(fmt).to_string(),
+        }
+    }
+
+    /// Shift a UTC datetime into this zone and return its local calendar date.
+    fn naive_date(&self, dt: DateTime<Utc>) -> NaiveDate {
+        match self {
+            Timezone::Named(tz) => dt.with_timezone(tz).naive_local().date(),
+            Timezone::Fixed(offset) => dt.with_timezone(offset).naive_local().date(),
+        }
+    }
 }
 
 pub async fn group_by_date(
@@ -68,51 +126,239 @@ pub async fn group_by_date(
         GroupByDateArgs {
             column_name,
             format,
+            period,
+            timezone,
+            every,
         },
         input,
     ) = args.process(&registry).await?;
     let values: Vec<Value> = input.collect().await;
 
     if values.is_empty() {
-        Err(ShellError::labeled_error(
+        return Err(ShellError::labeled_error(
             "Expected table from pipeline",
             "requires a table input",
             name,
-        ))
-    } else {
-        let grouper = if let Some(Tagged { item: fmt, tag: _ }) = format {
-            Grouper::ByDate(Some(fmt))
-        } else {
-            Grouper::ByDate(None)
-        };
-
-        match grouper {
-            Grouper::ByDate(None) => {
-                match crate::utils::data::group(
-                    column_name,
-                    &values,
-                    Some(Box::new(|row: &Value| row.format("%Y-%b-%d"))),
-                    &name,
-                ) {
-                    Ok(grouped) => Ok(OutputStream::one(ReturnSuccess::value(grouped))),
-                    Err(err) => Err(err),
-                }
-            }
-            Grouper::ByDate(Some(fmt)) => {
-                match crate::utils::data::group(
-                    column_name,
-                    &values,
-                    Some(Box::new(move |row: &Value| row.format(&fmt))),
-                    &name,
-                ) {
-                    Ok(grouped) => Ok(OutputStream::one(ReturnSuccess::value(grouped))),
-                    Err(err) => Err(err),
-                }
-            }
+        ));
+    }
+
+    let grouper = match (format, period, every) {
+        (_, Some(_), Some(every)) => {
+            return Err(ShellError::labeled_error(
+                "Cannot use --every and --period together",
+                "conflicts with --period",
+                every.tag(),
+            ));
         }
+        (Some(_), Some(period), None) => {
+            return Err(ShellError::labeled_error(
+                "Cannot use --format and --period together",
+                "conflicts with --format",
+                period.tag(),
+            ));
+        }
+        (format, None, Some(every)) => Grouper::ByEvery {
+            interval: parse_every(&every)?,
+            format: format.map(|fmt| fmt.item),
+        },
+        (_, Some(period), None) => Grouper::ByPeriod(parse_period(&period)?),
+        (Some(Tagged { item: fmt, tag: _ }), None, None) => Grouper::ByDate(Some(fmt)),
+        (None, None, None) => Grouper::ByDate(None),
+    };
+
+    let timezone = match timezone {
+        Some(tz) => Some(parse_timezone(&tz)?),
+        None => None,
+    };
+
+    let result = match grouper {
+        Grouper::ByDate(None) => crate::utils::data::group(
+            column_name,
+            &values,
+            Some(Box::new(move |row: &Value| match &timezone {
+                Some(tz) => Ok(tz.format(as_date(row)?, "%Y-%b-%d")),
+                None => row.format("%Y-%b-%d"),
+            })),
+            &name,
+        ),
+        Grouper::ByDate(Some(fmt)) => crate::utils::data::group(
+            column_name,
+            &values,
+            Some(Box::new(move |row: &Value| match &timezone {
+                Some(tz) => Ok(tz.format(as_date(row)?, &fmt)),
+                None => row.format(&fmt),
+            })),
+            &name,
+        ),
+        Grouper::ByPeriod(period) => crate::utils::data::group(
+            column_name,
+            &values,
+            Some(Box::new(move |row: &Value| {
+                let dt = as_date(row)?;
+                let date = match &timezone {
+                    Some(tz) => tz.naive_date(dt),
+                    None => dt.naive_utc().date(),
+                };
+                Ok(floor_to_period(date, &period))
+            })),
+            &name,
+        ),
+        Grouper::ByEvery { interval, format } => crate::utils::data::group(
+            column_name,
+            &values,
+            Some(Box::new(move |row: &Value| {
+                let ts = as_date(row)?.timestamp();
+                let bucket = Utc.timestamp(ts - ts.rem_euclid(interval), 0);
+                let fmt = format.as_deref().unwrap_or("%Y-%m-%d %H:%M:%S");
+                Ok(match &timezone {
+                    Some(tz) => tz.format(bucket, fmt),
+                    None => bucket.format(fmt).to_string(),
+                })
+            })),
+            &name,
+        ),
+    };
+
+    match result {
+        Ok(grouped) => Ok(OutputStream::one(ReturnSuccess::value(grouped))),
+        Err(err) => Err(err),
     }
 }
 
+fn parse_period(period: &Tagged<String>) -> Result<Period, ShellError> {
+    match period.item.as_str() {
+        "day" => Ok(Period::Day),
+        "week" => Ok(Period::Week),
+        "month" => Ok(Period::Month),
+        "quarter" => Ok(Period::Quarter),
+        "year" => Ok(Period::Year),
+        other => Err(ShellError::labeled_error(
+            format!("Unknown period '{}'", other),
+            "expected one of day, week, month, quarter, year",
+            period.tag(),
+        )),
+    }
+}
+
+/// Pull a UTC datetime out of a row's column value, surfacing a labeled error
+/// anchored at the offending value when it is not a date.
+fn as_date(row: &Value) -> Result<DateTime<Utc>, ShellError> {
+    match &row.value {
+        UntaggedValue::Primitive(Primitive::Date(dt)) => Ok(*dt),
+        _ => Err(ShellError::labeled_error(
+            "Could not parse as a date",
+            format!("'{}' is not a date", row.convert_to_string()),
+            &row.tag,
+        )),
+    }
+}
+
+/// Parse an `--every` duration string (`30sec`, `15min`, `1h`, `7day`) into a
+/// whole number of seconds, rejecting zero or negative intervals.
+fn parse_every(every: &Tagged<String>) -> Result<i64, ShellError> {
+    let raw = every.item.trim();
+    let split = raw
+        .find(|c: char| !c.is_ascii_digit() && c != '-')
+        .unwrap_or_else(|| raw.len()); // whole string is the amount (missing unit) -> caught below
+
+    let (amount, unit) = raw.split_at(split);
+
+    let amount: i64 = amount.parse().map_err(|_| {
+        ShellError::labeled_error(
+            format!("Invalid interval '{}'", raw),
+            "expected a number followed by a unit, e.g. 15min",
+            every.tag(),
+        )
+    })?;
+
+    let unit_secs = match unit {
+        "sec" | "secs" => 1,
+        "min" | "mins" => 60,
+        "h" | "hr" | "hour" | "hours" => 3600,
+        "day" | "days" => 86_400,
+        other => {
+            return Err(ShellError::labeled_error(
+                format!("Unknown interval unit '{}'", other),
+                "expected one of sec, min, h, day",
+                every.tag(),
+            ));
+        }
+    };
+
+    let interval = amount * unit_secs;
+    if interval <= 0 {
+        return Err(ShellError::labeled_error(
+            "Interval must be positive",
+            "expected a duration greater than zero",
+            every.tag(),
+        ));
+    }
+
+    Ok(interval)
+}
+
+/// Resolve a `--timezone` argument, accepting either an IANA zone name or a
+/// numeric `+HH:MM` / `-HH:MM` offset, with a labeled error for anything else.
+fn parse_timezone(timezone: &Tagged<String>) -> Result<Timezone, ShellError> {
+    let raw = timezone.item.as_str();
+
+    if let Ok(tz) = raw.parse::<Tz>() {
+        return Ok(Timezone::Named(tz));
+    }
+
+    if let Some(offset) = parse_fixed_offset(raw) {
+        return Ok(Timezone::Fixed(offset));
+    }
+
+    Err(ShellError::labeled_error(
+        format!("Unknown timezone '{}'", raw),
+        "expected an IANA name (America/New_York) or an offset (+05:30)",
+        timezone.tag(),
+    ))
+}
+
+/// Parse a signed `+HH:MM` / `-HH:MM` offset into a `FixedOffset`.
+fn parse_fixed_offset(raw: &str) -> Option<FixedOffset> {
+    let (sign, rest) = match raw.strip_prefix('+') {
+        Some(rest) => (1, rest),
+        None => match raw.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => return None,
+        },
+    };
+
+    let mut parts = rest.splitn(2, ':');
+    let hours: i32 = parts.next()?.parse().ok()?;
+    let minutes: i32 = parts.next()?.parse().ok()?;
+    if !(0..=59).contains(&minutes) {
+        return None;
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Floor a calendar date to the start of its period and render it in a
+/// canonical, sortable `%Y-%m-%d` form so downstream `sort-by` stays chronological.
+fn floor_to_period(date: NaiveDate, period: &Period) -> String {
+    let floored = match period {
+        Period::Day => date,
+        Period::Week => date - Duration::days(date.weekday().num_days_from_monday() as i64),
+        Period::Month => date.with_day(1).unwrap_or(date),
+        Period::Quarter => {
+            let quarter_month = (date.month() - 1) / 3 * 3 + 1;
+            date.with_day(1)
+                .and_then(|d| d.with_month(quarter_month))
+                .unwrap_or(date)
+        }
+        Period::Year => date
+            .with_day(1)
+            .and_then(|d| d.with_month(1))
+            .unwrap_or(date),
+    };
+
+    floored.format("%Y-%m-%d").to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::GroupByDate;